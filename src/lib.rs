@@ -0,0 +1,21 @@
+//! Parses messages from the Twitter Streaming API.
+
+extern crate chrono;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
+
+pub mod message;
+pub mod tweet;
+pub mod types;
+pub mod user;
+pub mod util;
+
+pub use message::event::{Event, EventKind};
+pub use tweet::Tweet;
+pub use user::{User, UserId};
+
+// TODO: `message::event` also references a `List` type (the `target_object`
+// of `ListCreated`/`ListDestroyed`/…), which has no implementation in this
+// tree yet and is not declared here.
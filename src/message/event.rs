@@ -8,6 +8,7 @@ use serde::de::{
     MapAccess,
     Visitor,
 };
+use serde::ser::{Serialize, SerializeMap, Serializer};
 
 use {List, Tweet, User};
 use types::{DateTime, JsonValue};
@@ -20,12 +21,14 @@ use util;
 /// 1. [Streaming message types — Twitter Developers][1]
 ///
 /// [1]: https://dev.twitter.com/streaming/overview/messages-types#Events_event
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct Event<'a> {
+    #[serde(serialize_with = "::util::serialize_datetime")]
     pub created_at: DateTime,
 
     /// An object which indicates the name of the event and contains
     /// an optional object which represents the target of the event.
+    #[serde(flatten)]
     pub event: EventKind<'a>,
 
     pub target: User<'a>,
@@ -33,6 +36,19 @@ pub struct Event<'a> {
     pub source: User<'a>,
 }
 
+impl<'a> Event<'a> {
+    /// Converts `self` into a fully owned `Event` whose lifetime is
+    /// `'static`, detaching it from the buffer it was deserialized from.
+    pub fn into_owned(self) -> Event<'static> {
+        Event {
+            created_at: self.created_at,
+            event: self.event.into_owned(),
+            target: self.target.into_owned(),
+            source: self.source.into_owned(),
+        }
+    }
+}
+
 macro_rules! impl_event {
     (
         $(#[$attr:meta])*
@@ -175,6 +191,50 @@ macro_rules! impl_event {
                 write!(f, "a map")
             }
         }
+
+        impl<'a> Serialize for $T<'a> {
+            fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+                match *self {
+                    $($T::$Container(ref c) => {
+                        let mut map = s.serialize_map(Some(2))?;
+                        map.serialize_entry("event", $c_tag)?;
+                        map.serialize_entry("target_object", c)?;
+                        map.end()
+                    },)*
+                    $($T::$Label => {
+                        let mut map = s.serialize_map(Some(1))?;
+                        map.serialize_entry("event", $l_tag)?;
+                        map.end()
+                    },)*
+                    $T::$Custom(ref name, ref obj) => {
+                        let mut map = s.serialize_map(Some(if obj.is_some() { 2 } else { 1 }))?;
+                        // `target_object` goes first: for an unknown event name
+                        // the deserializer only keeps a target object that
+                        // precedes the `event` key.
+                        if let Some(ref obj) = *obj {
+                            map.serialize_entry("target_object", obj)?;
+                        }
+                        map.serialize_entry("event", name)?;
+                        map.end()
+                    },
+                }
+            }
+        }
+
+        impl<'a> $T<'a> {
+            /// Converts `self` into a fully owned value whose lifetime is
+            /// `'static`, detaching it from the buffer it was deserialized
+            /// from.
+            pub fn into_owned(self) -> $T<'static> {
+                match self {
+                    $($T::$Container(c) => $T::$Container(Box::new(c.into_owned())),)*
+                    $($T::$Label => $T::$Label,)*
+                    $T::$Custom(name, obj) => {
+                        $T::$Custom(::std::borrow::Cow::Owned(name.into_owned()), obj)
+                    },
+                }
+            }
+        }
     };
 }
 
@@ -192,8 +252,10 @@ impl_event! {
     /// | User removes a block                | `Unblock`              | Current user       | Unblocked user |
     /// | User favorites a Tweet              | `Favorite`             | Current user       | Tweet author   |
     /// | User's Tweet is favorited           | `Favorite`             | Favoriting user    | Current user   |
+    /// | User's Retweet is favorited         | `FavoritedRetweet`     | Favoriting user    | Current user   |
     /// | User unfavorites a Tweet            | `Unfavorite`           | Current user       | Tweet author   |
     /// | User's Tweet is unfavorited         | `Unfavorite`           | Unfavoriting user  | Current user   |
+    /// | User's Retweet is retweeted         | `RetweetedRetweet`     | Retweeting user    | Current user   |
     /// | User follows someone                | `Follow`               | Current user       | Followed user  |
     /// | User is followed                    | `Follow`               | Following user     | Current user   |
     /// | User unfollows someone              | `Unfollow`             | Current user       | Followed user  |
@@ -214,7 +276,9 @@ impl_event! {
     #[derive(Clone, Debug, PartialEq)]
     pub enum EventKind<'a> {
         Favorite("favorite", Box<Tweet<'a>>),
+        FavoritedRetweet("favorited_retweet", Box<Tweet<'a>>),
         Unfavorite("unfavorite", Box<Tweet<'a>>),
+        RetweetedRetweet("retweeted_retweet", Box<Tweet<'a>>),
         ListCreated("list_created", Box<List<'a>>),
         ListDestroyed("list_destroyed", Box<List<'a>>),
         ListUpdated("list_updated", Box<List<'a>>),
@@ -234,3 +298,76 @@ impl_event! {
         Custom(_, _),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use serde_json;
+    use tweet;
+    use user;
+
+    use super::{Event, EventKind};
+
+    #[test]
+    fn event_round_trips_through_json() {
+        let event = Event {
+            created_at: ::util::parse_datetime("Tue Jun 11 23:05:08 +0000 2019").unwrap(),
+            event: EventKind::Block,
+            target: user::test_user(),
+            source: user::test_user(),
+        };
+
+        let json = serde_json::to_string(&event).unwrap();
+        let parsed: Event = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(event, parsed);
+    }
+
+    #[test]
+    fn favorite_event_round_trips_through_json() {
+        let event = Event {
+            created_at: ::util::parse_datetime("Tue Jun 11 23:05:08 +0000 2019").unwrap(),
+            event: EventKind::Favorite(Box::new(tweet::test_tweet())),
+            target: user::test_user(),
+            source: user::test_user(),
+        };
+
+        let json = serde_json::to_string(&event).unwrap();
+        let parsed: Event = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(event, parsed);
+    }
+
+    #[test]
+    fn custom_event_without_target_object_round_trips() {
+        let event = Event {
+            created_at: ::util::parse_datetime("Tue Jun 11 23:05:08 +0000 2019").unwrap(),
+            event: EventKind::Custom(Cow::Borrowed("some_future_event"), None),
+            target: user::test_user(),
+            source: user::test_user(),
+        };
+
+        let json = serde_json::to_string(&event).unwrap();
+        let parsed: Event = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(event, parsed);
+    }
+
+    #[test]
+    fn custom_event_with_target_object_round_trips() {
+        let obj: ::types::JsonValue =
+            serde_json::from_str(r#"{"foo":"bar","count":3}"#).unwrap();
+        let event = Event {
+            created_at: ::util::parse_datetime("Tue Jun 11 23:05:08 +0000 2019").unwrap(),
+            event: EventKind::Custom(Cow::Borrowed("some_future_event"), Some(obj)),
+            target: user::test_user(),
+            source: user::test_user(),
+        };
+
+        let json = serde_json::to_string(&event).unwrap();
+        let parsed: Event = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(event, parsed);
+    }
+}
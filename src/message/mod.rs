@@ -0,0 +1,5 @@
+//! Streaming message types
+
+pub mod event;
+
+pub use self::event::{Event, EventKind};
@@ -0,0 +1,462 @@
+//! Tweets
+
+use std::borrow::Cow;
+
+use types::{DateTime, JsonValue};
+use user::{self, User, UserId};
+
+/// Represents a Tweet.
+///
+/// # Reference
+///
+/// 1. [Tweets — Twitter Developers](https://dev.twitter.com/overview/api/tweets)
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Tweet<'a> {
+    // pub contributors: Option<Vec<u64>>, // deprecated
+
+    /// Represents the geographic location of this Tweet as reported by the
+    /// user or client application.
+    #[serde(default)]
+    pub coordinates: Option<JsonValue>,
+
+    /// The UTC datetime that the Tweet was posted.
+    #[serde(serialize_with = "::util::serialize_datetime")]
+    #[serde(deserialize_with = "::util::deserialize_datetime")]
+    pub created_at: DateTime,
+
+    /// Entities which have been parsed out of the text of the Tweet.
+    #[serde(borrow)]
+    pub entities: Entities<'a>,
+
+    /// Indicates approximately how many times this Tweet has been liked by
+    /// Twitter users.
+    pub favorite_count: u64,
+
+    /// *Perspectival*. Indicates whether this Tweet has been liked by the
+    /// authenticating user.
+    #[serde(default)]
+    pub favorited: Option<bool>,
+
+    /// Present when a Tweet exceeds 140 characters; carries the
+    /// fully-expanded text of a truncated Tweet.
+    #[serde(borrow)]
+    #[serde(default)]
+    pub extended_tweet: Option<ExtendedTweet<'a>>,
+
+    /// The integer representation of the unique identifier for this Tweet.
+    pub id: TweetId,
+
+    // pub id_str: String,
+
+    /// If the represented Tweet is a reply, the original Tweet’s id.
+    #[serde(default)]
+    pub in_reply_to_status_id: Option<TweetId>,
+
+    /// If the represented Tweet is a reply, the screen name of the original
+    /// Tweet’s author.
+    #[serde(borrow)]
+    #[serde(default)]
+    #[serde(deserialize_with = "::util::deserialize_opt_cow_str")]
+    pub in_reply_to_screen_name: Option<Cow<'a, str>>,
+
+    /// If the represented Tweet is a reply, the original Tweet’s author’s id.
+    #[serde(default)]
+    pub in_reply_to_user_id: Option<UserId>,
+
+    /// Indicates whether this is a Quote Tweet.
+    pub is_quote_status: bool,
+
+    /// When present, indicates a [BCP 47][1] language identifier corresponding
+    /// to the machine-detected language of the Tweet text, or `"und"` if
+    /// no language could be detected.
+    ///
+    /// [1]: http://tools.ietf.org/html/bcp47
+    #[serde(borrow)]
+    #[serde(default)]
+    #[serde(deserialize_with = "::util::deserialize_opt_cow_str")]
+    pub lang: Option<Cow<'a, str>>,
+
+    /// A [Twitter Place][1] object, present when the Tweet is associated with
+    /// (but not necessarily originating from) a place.
+    ///
+    /// [1]: https://dev.twitter.com/overview/api/places
+    #[serde(default)]
+    pub place: Option<JsonValue>,
+
+    /// This field only surfaces when a Tweet contains a link. The meaning of
+    /// the field doesn’t pertain to the Tweet content itself, but instead
+    /// it is an indicator that the URL contained in the Tweet may contain
+    /// content or media identified as sensitive content.
+    #[serde(default)]
+    pub possibly_sensitive: Option<bool>,
+
+    /// If this Tweet is a Quote Tweet, the id of the quoted Tweet.
+    #[serde(default)]
+    pub quoted_status_id: Option<TweetId>,
+
+    /// Users can amplify the broadcast of Tweets authored by other users
+    /// by retweeting. This field is set to the original Tweet when this
+    /// Tweet is a Retweet.
+    #[serde(borrow)]
+    #[serde(default)]
+    pub retweeted_status: Option<Box<Tweet<'a>>>,
+
+    /// Number of times this Tweet has been retweeted.
+    pub retweet_count: u64,
+
+    /// *Perspectival*. Indicates whether this Tweet has been retweeted by
+    /// the authenticating user.
+    #[serde(default)]
+    pub retweeted: Option<bool>,
+
+    /// Utility used to post the Tweet, as an HTML-formatted string.
+    #[serde(borrow)]
+    pub source: Cow<'a, str>,
+
+    /// The actual UTF-8 text of the status update.
+    #[serde(borrow)]
+    pub text: Cow<'a, str>,
+
+    /// Indicates whether the value of `text` was truncated, for example as a
+    /// result of a retweet exceeding the original Tweet text length limit.
+    pub truncated: bool,
+
+    /// The user who posted this Tweet.
+    #[serde(borrow)]
+    pub user: User<'a>,
+}
+
+impl<'a> Tweet<'a> {
+    /// Returns the display text of this Tweet, resolving `extended_tweet`
+    /// and rewriting `t.co` links the way the Twitter web client does.
+    pub fn full_text(&self) -> Cow<'a, str> {
+        if let Some(ref rt) = self.retweeted_status {
+            return rt.full_text();
+        }
+
+        let text: Cow<'a, str> = if self.truncated {
+            match self.extended_tweet {
+                Some(ref et) => et.full_text.clone(),
+                None => self.text.clone(),
+            }
+        } else {
+            self.text.clone()
+        };
+
+        let text = if text.contains('&') {
+            Cow::Owned(
+                text.replace("&amp;", "&")
+                    .replace("&lt;", "<")
+                    .replace("&gt;", ">"),
+            )
+        } else {
+            text
+        };
+
+        self.entities.urls.iter().fold(text, |text, url| {
+            let is_quoted_url = self.is_quote_status
+                && self.quoted_status_id.map_or(false, |id| {
+                    url.expanded_url.ends_with(&format!("/{}", id))
+                });
+
+            if is_quoted_url {
+                Cow::Owned(text.replace(&*url.url, ""))
+            } else if text.contains(&*url.url) {
+                Cow::Owned(text.replace(&*url.url, &*url.expanded_url))
+            } else {
+                text
+            }
+        })
+    }
+
+    /// Converts `self` into a fully owned `Tweet` whose lifetime is
+    /// `'static`, detaching it from the buffer it was deserialized from.
+    pub fn into_owned(self) -> Tweet<'static> {
+        Tweet {
+            coordinates: self.coordinates,
+            created_at: self.created_at,
+            entities: self.entities.into_owned(),
+            favorite_count: self.favorite_count,
+            favorited: self.favorited,
+            extended_tweet: self.extended_tweet.map(ExtendedTweet::into_owned),
+            id: self.id,
+            in_reply_to_status_id: self.in_reply_to_status_id,
+            in_reply_to_screen_name: user::owned(self.in_reply_to_screen_name),
+            in_reply_to_user_id: self.in_reply_to_user_id,
+            is_quote_status: self.is_quote_status,
+            lang: user::owned(self.lang),
+            place: self.place,
+            possibly_sensitive: self.possibly_sensitive,
+            quoted_status_id: self.quoted_status_id,
+            retweeted_status: self.retweeted_status.map(|rt| Box::new(rt.into_owned())),
+            retweet_count: self.retweet_count,
+            retweeted: self.retweeted,
+            source: Cow::Owned(self.source.into_owned()),
+            text: Cow::Owned(self.text.into_owned()),
+            truncated: self.truncated,
+            user: self.user.into_owned(),
+        }
+    }
+}
+
+/// Entities which have been parsed out of the text of a Tweet.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Entities<'a> {
+    /// Represents hashtags included in the text of the Tweet.
+    #[serde(borrow)]
+    pub hashtags: Vec<HashtagEntity<'a>>,
+
+    /// Represents media elements uploaded with the Tweet.
+    #[serde(default)]
+    pub media: Option<Vec<MediaEntity<'a>>>,
+
+    /// Represents URLs included in the text of the Tweet.
+    #[serde(borrow)]
+    pub urls: Vec<UrlEntity<'a>>,
+
+    /// Represents other Twitter users mentioned in the text of the Tweet.
+    #[serde(borrow)]
+    pub user_mentions: Vec<UserMentionEntity<'a>>,
+}
+
+impl<'a> Entities<'a> {
+    fn into_owned(self) -> Entities<'static> {
+        Entities {
+            hashtags: self
+                .hashtags
+                .into_iter()
+                .map(HashtagEntity::into_owned)
+                .collect(),
+            media: self
+                .media
+                .map(|m| m.into_iter().map(MediaEntity::into_owned).collect()),
+            urls: self.urls.into_iter().map(UrlEntity::into_owned).collect(),
+            user_mentions: self
+                .user_mentions
+                .into_iter()
+                .map(UserMentionEntity::into_owned)
+                .collect(),
+        }
+    }
+}
+
+/// Represents a hashtag included in the text of a Tweet.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct HashtagEntity<'a> {
+    /// Name of the hashtag, without the leading `#` character.
+    #[serde(borrow)]
+    pub text: Cow<'a, str>,
+}
+
+impl<'a> HashtagEntity<'a> {
+    fn into_owned(self) -> HashtagEntity<'static> {
+        HashtagEntity {
+            text: Cow::Owned(self.text.into_owned()),
+        }
+    }
+}
+
+/// Represents a media element uploaded with a Tweet.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct MediaEntity<'a> {
+    /// An HTTPS-based URL pointing to the media file.
+    #[serde(borrow)]
+    pub media_url_https: Cow<'a, str>,
+
+    /// Type of the uploaded media.
+    #[serde(borrow)]
+    #[serde(rename = "type")]
+    pub kind: Cow<'a, str>,
+}
+
+impl<'a> MediaEntity<'a> {
+    fn into_owned(self) -> MediaEntity<'static> {
+        MediaEntity {
+            media_url_https: Cow::Owned(self.media_url_https.into_owned()),
+            kind: Cow::Owned(self.kind.into_owned()),
+        }
+    }
+}
+
+/// Represents a URL included in the text of a Tweet.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct UrlEntity<'a> {
+    /// Wrapped URL, corresponding to the value embedded directly into the
+    /// raw Tweet text.
+    #[serde(borrow)]
+    pub url: Cow<'a, str>,
+
+    /// Fully resolved URL.
+    #[serde(borrow)]
+    pub expanded_url: Cow<'a, str>,
+}
+
+impl<'a> UrlEntity<'a> {
+    fn into_owned(self) -> UrlEntity<'static> {
+        UrlEntity {
+            url: Cow::Owned(self.url.into_owned()),
+            expanded_url: Cow::Owned(self.expanded_url.into_owned()),
+        }
+    }
+}
+
+/// Represents another Twitter user mentioned in the text of a Tweet.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct UserMentionEntity<'a> {
+    /// The id of the mentioned user.
+    pub id: UserId,
+
+    /// The screen name of the mentioned user.
+    #[serde(borrow)]
+    pub screen_name: Cow<'a, str>,
+}
+
+impl<'a> UserMentionEntity<'a> {
+    fn into_owned(self) -> UserMentionEntity<'static> {
+        UserMentionEntity {
+            id: self.id,
+            screen_name: Cow::Owned(self.screen_name.into_owned()),
+        }
+    }
+}
+
+/// Extended Tweet payload, present when a Tweet's `text` was truncated
+/// to fit the legacy 140 character Tweet length limit.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ExtendedTweet<'a> {
+    /// The full, untruncated text of the Tweet.
+    #[serde(borrow)]
+    pub full_text: Cow<'a, str>,
+}
+
+impl<'a> ExtendedTweet<'a> {
+    fn into_owned(self) -> ExtendedTweet<'static> {
+        ExtendedTweet {
+            full_text: Cow::Owned(self.full_text.into_owned()),
+        }
+    }
+}
+
+/// Numerical ID of a Tweet.
+pub type TweetId = u64;
+
+/// A minimal but fully populated `Tweet`, for other modules' tests to build
+/// `Event` fixtures on top of.
+#[cfg(test)]
+pub(crate) fn test_tweet() -> Tweet<'static> {
+    Tweet {
+        coordinates: None,
+        created_at: ::util::parse_datetime("Tue Jun 11 23:05:08 +0000 2019").unwrap(),
+        entities: Entities {
+            hashtags: Vec::new(),
+            media: None,
+            urls: Vec::new(),
+            user_mentions: Vec::new(),
+        },
+        favorite_count: 0,
+        favorited: None,
+        extended_tweet: None,
+        id: 1,
+        in_reply_to_status_id: None,
+        in_reply_to_screen_name: None,
+        in_reply_to_user_id: None,
+        is_quote_status: false,
+        lang: None,
+        place: None,
+        possibly_sensitive: None,
+        quoted_status_id: None,
+        retweeted_status: None,
+        retweet_count: 0,
+        retweeted: None,
+        source: Cow::Borrowed("<a href=\"https://mobile.twitter.com\">Twitter Web App</a>"),
+        text: Cow::Borrowed("hello world"),
+        truncated: false,
+        user: ::user::test_user(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{ExtendedTweet, Tweet, UrlEntity};
+
+    fn tweet<'a>(text: &'a str, truncated: bool) -> Tweet<'a> {
+        Tweet {
+            text: Cow::Borrowed(text),
+            truncated,
+            ..super::test_tweet()
+        }
+    }
+
+    #[test]
+    fn full_text_uses_plain_text_when_not_truncated() {
+        let t = tweet("hello world", false);
+        assert_eq!(t.full_text(), "hello world");
+    }
+
+    #[test]
+    fn full_text_uses_extended_tweet_when_truncated() {
+        let mut t = tweet("hello wor…", true);
+        t.extended_tweet = Some(ExtendedTweet {
+            full_text: Cow::Borrowed("hello world, the rest of it"),
+        });
+        assert_eq!(t.full_text(), "hello world, the rest of it");
+    }
+
+    #[test]
+    fn full_text_unescapes_html_entities() {
+        let t = tweet("Tom &amp; Jerry &lt;3 &gt;", false);
+        assert_eq!(t.full_text(), "Tom & Jerry <3 >");
+    }
+
+    #[test]
+    fn full_text_expands_tco_urls() {
+        let mut t = tweet("check this out https://t.co/abc123", false);
+        t.entities.urls.push(UrlEntity {
+            url: Cow::Borrowed("https://t.co/abc123"),
+            expanded_url: Cow::Borrowed("https://example.com/article"),
+        });
+        assert_eq!(t.full_text(), "check this out https://example.com/article");
+    }
+
+    #[test]
+    fn full_text_drops_trailing_quoted_tweet_url() {
+        let mut t = tweet("look at this https://t.co/abc123", false);
+        t.is_quote_status = true;
+        t.quoted_status_id = Some(42);
+        t.entities.urls.push(UrlEntity {
+            url: Cow::Borrowed("https://t.co/abc123"),
+            expanded_url: Cow::Borrowed("https://twitter.com/user/status/42"),
+        });
+        assert_eq!(t.full_text(), "look at this ");
+    }
+
+    #[test]
+    fn full_text_keeps_unrelated_url_with_matching_digit_suffix() {
+        let mut t = tweet("read this https://t.co/abc123 too", false);
+        t.is_quote_status = true;
+        t.quoted_status_id = Some(1);
+        t.entities.urls.push(UrlEntity {
+            url: Cow::Borrowed("https://t.co/abc123"),
+            expanded_url: Cow::Borrowed("https://example.com/article/21"),
+        });
+        assert_eq!(
+            t.full_text(),
+            "read this https://example.com/article/21 too"
+        );
+    }
+
+    #[test]
+    fn full_text_recurses_into_retweeted_status() {
+        let mut original = tweet("hello wor…", true);
+        original.extended_tweet = Some(ExtendedTweet {
+            full_text: Cow::Borrowed("hello world, the rest of it"),
+        });
+
+        let mut rt = tweet("RT @user: hello wor…", true);
+        rt.retweeted_status = Some(Box::new(original));
+
+        assert_eq!(rt.full_text(), "hello world, the rest of it");
+    }
+}
@@ -0,0 +1,29 @@
+//! Value types shared across message types.
+
+use std::borrow::Cow;
+
+/// The UTC datetime type used throughout this crate for Twitter's
+/// `created_at`-style fields.
+pub type DateTime = ::chrono::DateTime<::chrono::Utc>;
+
+/// An arbitrary JSON payload, used where this crate does not model a value
+/// as a typed structure (for example, an `Event`'s `target_object` for an
+/// event name this crate does not recognize).
+pub type JsonValue = ::serde_json::Value;
+
+/// Indicates whether the content being withheld from a user or a Tweet is
+/// the `Status` or the `User` itself.
+///
+/// # Reference
+///
+/// 1. [Withheld Content — Twitter Developers](https://dev.twitter.com/overview/api/upcoming-changes-to-content-withholdings)
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct WithheldScope<'a>(#[serde(borrow)] pub Cow<'a, str>);
+
+impl<'a> WithheldScope<'a> {
+    /// Converts `self` into a fully owned `WithheldScope` whose lifetime is
+    /// `'static`, detaching it from the buffer it was deserialized from.
+    pub fn into_owned(self) -> WithheldScope<'static> {
+        WithheldScope(Cow::Owned(self.0.into_owned()))
+    }
+}
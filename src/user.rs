@@ -9,7 +9,7 @@ use types::{DateTime, WithheldScope};
 /// # Reference
 ///
 /// 1. [Users — Twitter Developers](https://dev.twitter.com/overview/api/users)
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct User<'a> {
     /// Indicates that the user has an account with “contributor mode” enabled,
     /// allowing for Tweets issued by the user to be co-authored
@@ -17,6 +17,7 @@ pub struct User<'a> {
     pub contributors_enabled: bool,
 
     /// The UTC datetime that the user account was created on Twitter.
+    #[serde(serialize_with = "::util::serialize_datetime")]
     #[serde(deserialize_with = "::util::deserialize_datetime")]
     pub created_at: DateTime,
 
@@ -231,5 +232,104 @@ pub struct User<'a> {
     pub withheld_scope: Option<WithheldScope<'a>>,
 }
 
+impl<'a> User<'a> {
+    /// Converts `self` into a fully owned `User` whose lifetime is
+    /// `'static`, detaching it from the buffer it was deserialized from.
+    pub fn into_owned(self) -> User<'static> {
+        User {
+            contributors_enabled: self.contributors_enabled,
+            created_at: self.created_at,
+            default_profile: self.default_profile,
+            default_profile_image: self.default_profile_image,
+            description: owned(self.description),
+            favourites_count: self.favourites_count,
+            follow_request_sent: self.follow_request_sent,
+            followers_count: self.followers_count,
+            friends_count: self.friends_count,
+            geo_enabled: self.geo_enabled,
+            id: self.id,
+            is_translator: self.is_translator,
+            lang: Cow::Owned(self.lang.into_owned()),
+            listed_count: self.listed_count,
+            location: owned(self.location),
+            name: Cow::Owned(self.name.into_owned()),
+            profile_background_color: Cow::Owned(self.profile_background_color.into_owned()),
+            profile_background_image_url:
+                Cow::Owned(self.profile_background_image_url.into_owned()),
+            profile_background_image_url_https:
+                Cow::Owned(self.profile_background_image_url_https.into_owned()),
+            profile_background_tile: self.profile_background_tile,
+            profile_banner_url: owned(self.profile_banner_url),
+            profile_image_url: Cow::Owned(self.profile_image_url.into_owned()),
+            profile_image_url_https: Cow::Owned(self.profile_image_url_https.into_owned()),
+            profile_link_color: Cow::Owned(self.profile_link_color.into_owned()),
+            profile_sidebar_border_color:
+                Cow::Owned(self.profile_sidebar_border_color.into_owned()),
+            profile_sidebar_fill_color: Cow::Owned(self.profile_sidebar_fill_color.into_owned()),
+            profile_text_color: Cow::Owned(self.profile_text_color.into_owned()),
+            profile_use_background_image: self.profile_use_background_image,
+            protected: self.protected,
+            screen_name: Cow::Owned(self.screen_name.into_owned()),
+            statuses_count: self.statuses_count,
+            time_zone: owned(self.time_zone),
+            url: owned(self.url),
+            utc_offset: self.utc_offset,
+            verified: self.verified,
+            withheld_in_countries: owned(self.withheld_in_countries),
+            withheld_scope: self.withheld_scope.map(WithheldScope::into_owned),
+        }
+    }
+}
+
+/// Converts an `Option<Cow<str>>` into one that owns its data.
+pub(crate) fn owned(s: Option<Cow<str>>) -> Option<Cow<'static, str>> {
+    s.map(|s| Cow::Owned(s.into_owned()))
+}
+
 /// Numerical ID of a user.
 pub type UserId = u64;
+
+/// A minimal but fully populated `User`, for other modules' tests to build
+/// `Event`/`Tweet` fixtures on top of.
+#[cfg(test)]
+pub(crate) fn test_user() -> User<'static> {
+    User {
+        contributors_enabled: false,
+        created_at: ::util::parse_datetime("Tue Jun 11 23:05:08 +0000 2019").unwrap(),
+        default_profile: true,
+        default_profile_image: false,
+        description: None,
+        favourites_count: 0,
+        follow_request_sent: None,
+        followers_count: 0,
+        friends_count: 0,
+        geo_enabled: false,
+        id: 1,
+        is_translator: false,
+        lang: Cow::Borrowed("en"),
+        listed_count: 0,
+        location: None,
+        name: Cow::Borrowed("Test User"),
+        profile_background_color: Cow::Borrowed("000000"),
+        profile_background_image_url: Cow::Borrowed(""),
+        profile_background_image_url_https: Cow::Borrowed(""),
+        profile_background_tile: false,
+        profile_banner_url: None,
+        profile_image_url: Cow::Borrowed(""),
+        profile_image_url_https: Cow::Borrowed(""),
+        profile_link_color: Cow::Borrowed("000000"),
+        profile_sidebar_border_color: Cow::Borrowed("000000"),
+        profile_sidebar_fill_color: Cow::Borrowed("000000"),
+        profile_text_color: Cow::Borrowed("000000"),
+        profile_use_background_image: false,
+        protected: false,
+        screen_name: Cow::Borrowed("testuser"),
+        statuses_count: 0,
+        time_zone: None,
+        url: None,
+        utc_offset: None,
+        verified: false,
+        withheld_in_countries: None,
+        withheld_scope: None,
+    }
+}
@@ -0,0 +1,83 @@
+//! Helpers for (de)serializing values in the shape Twitter sends them in.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::Deref;
+
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::Serializer;
+
+use types::DateTime;
+
+/// The `strftime`-style layout Twitter uses for `created_at` fields.
+const DATETIME_FORMAT: &str = "%a %b %d %H:%M:%S %z %Y";
+
+/// A `Cow<str>` that deserializes borrowing from the input when possible.
+pub struct CowStr<'a>(pub Cow<'a, str>);
+
+impl<'a> Deref for CowStr<'a> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for CowStr<'a> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        struct CowStrVisitor;
+
+        impl<'de> Visitor<'de> for CowStrVisitor {
+            type Value = Cow<'de, str>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a string")
+            }
+
+            fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+                Ok(Cow::Borrowed(v))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(Cow::Owned(v.to_owned()))
+            }
+        }
+
+        d.deserialize_str(CowStrVisitor).map(CowStr)
+    }
+}
+
+/// Parses a Twitter-formatted datetime string, e.g. the `created_at` field
+/// of a `Tweet` or `User`.
+pub fn parse_datetime(s: &str) -> Result<DateTime, ::chrono::ParseError> {
+    use chrono::TimeZone;
+
+    ::chrono::DateTime::parse_from_str(s, DATETIME_FORMAT)
+        .map(|dt| dt.with_timezone(&::chrono::Utc))
+}
+
+/// Formats a datetime back into the Twitter wire format, inverting
+/// `parse_datetime`.
+pub fn format_datetime(dt: &DateTime) -> String {
+    dt.format(DATETIME_FORMAT).to_string()
+}
+
+/// Deserializes a Twitter-formatted datetime string into a `DateTime`.
+pub fn deserialize_datetime<'de, D: Deserializer<'de>>(d: D) -> Result<DateTime, D::Error> {
+    let s = CowStr::deserialize(d)?;
+    parse_datetime(&s).map_err(de::Error::custom)
+}
+
+/// Serializes a `DateTime` back into the Twitter wire format.
+pub fn serialize_datetime<S: Serializer>(dt: &DateTime, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_str(&format_datetime(dt))
+}
+
+/// Deserializes an optional string field the way Twitter sends it: absent,
+/// `null`, and `""` are all treated as `None`.
+pub fn deserialize_opt_cow_str<'de, D: Deserializer<'de>>(
+    d: D,
+) -> Result<Option<Cow<'de, str>>, D::Error> {
+    let opt = Option::<CowStr>::deserialize(d)?;
+    Ok(opt.and_then(|s| if s.0.is_empty() { None } else { Some(s.0) }))
+}